@@ -0,0 +1,109 @@
+use std::marker::PhantomData;
+
+/// Represents an iterator that chains a fallible transform onto the Ok values.
+///
+/// This trait is implemented for iterators over `Result<T, E>`, allowing them to apply a
+/// closure that itself returns a `Result` to each Ok value, just like [`Result::and_then`].
+/// An error produced by the closure is yielded in place of that item; iteration does not
+/// stop there and continues with the next item.
+///
+/// # Implementations
+///
+/// Implementations of this trait must provide an implementation for the `and_then_ok`
+/// function, which receives a closure `f` that takes an Ok value of type `T` and returns a
+/// `Result<U, E>`. It returns an `AndThenOkIter` iterator that yields the closure's result
+/// directly for every Ok value, and passes every `Err` value through unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use map_ok::AndThenOk;
+///
+/// let input: Vec<Result<&str, &str>> = vec![Ok("1"), Err("oops"), Ok("x")];
+/// let mut iterator = input
+///     .into_iter()
+///     .and_then_ok(|s| s.parse::<i32>().map_err(|_| "parse error"));
+///
+/// assert_eq!(iterator.next(), Some(Ok(1)));
+/// assert_eq!(iterator.next(), Some(Err("oops")));
+/// assert_eq!(iterator.next(), Some(Err("parse error")));
+/// assert_eq!(iterator.next(), None);
+/// ```
+pub trait AndThenOk<T, E, F, U>: Sized
+where
+    F: Fn(T) -> Result<U, E>,
+{
+    type Iter: Iterator<Item = Result<U, E>>;
+
+    fn and_then_ok(self, f: F) -> Self::Iter;
+}
+
+impl<I, T, E, U, F> AndThenOk<T, E, F, U> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+    F: Fn(T) -> Result<U, E>,
+{
+    type Iter = AndThenOkIter<Self, T, E, U, F>;
+
+    fn and_then_ok(self, f: F) -> Self::Iter {
+        AndThenOkIter {
+            iter: self,
+            f,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+/// A special iterator adapter that applies a fallible closure to the `Ok` values of an
+/// underlying iterator, similar to `Result::and_then`, but passes every `Err` value through
+/// unchanged.
+///
+/// # Type arguments
+/// * `I` - The iterator itself.
+/// * `T` - The type of [`Ok`] variant of the iterated item.
+/// * `E` - The type of the [`Err`] variant of the iterated item.
+/// * `U` - The mapped type.
+/// * `F` - A [`Fn`] that maps from `T` to `Result<U, E>`.
+pub struct AndThenOkIter<I, T, E, U, F> {
+    iter: I,
+    f: F,
+    _phantom: PhantomData<MapFn<T, E, U>>,
+}
+
+/// Helper type to simplify type definition.
+type MapFn<T, E, U> = fn(T, E) -> (U, Result<T, E>);
+
+impl<I, T, E, U, F> Iterator for AndThenOkIter<I, T, E, U, F>
+where
+    I: Iterator<Item = Result<T, E>>,
+    F: Fn(T) -> Result<U, E>,
+{
+    type Item = Result<U, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next() {
+            Some(Ok(value)) => Some((self.f)(value)),
+            Some(Err(e)) => Some(Err(e)),
+            None => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn and_then_ok_works() {
+        let input: Vec<Result<&str, &str>> = vec![Ok("1"), Err("oops"), Ok("x"), Ok("3")];
+        let mut iterator = input
+            .into_iter()
+            .and_then_ok(|s| s.parse::<i32>().map_err(|_| "parse error"));
+
+        assert_eq!(iterator.next(), Some(Ok(1)));
+        assert_eq!(iterator.next(), Some(Err("oops")));
+        assert_eq!(iterator.next(), Some(Err("parse error")));
+        assert_eq!(iterator.next(), Some(Ok(3)));
+        assert_eq!(iterator.next(), None);
+    }
+}
@@ -0,0 +1,111 @@
+use std::marker::PhantomData;
+
+/// Represents an iterator that filters and maps the Ok values using the given closure.
+///
+/// This trait is implemented for iterators over `Result<T, E>`, allowing them to transform
+/// the Ok values using a closure that returns an [`Option`], keeping only the values for
+/// which the closure returns `Some`, while letting every `Err` value pass through unchanged.
+///
+/// # Implementations
+///
+/// Implementations of this trait must provide an implementation for the `filter_map_ok`
+/// function, which receives a closure `f` that takes an Ok value of type `T` and returns an
+/// `Option<U>`. It returns a `FilterMapOkIter` iterator that only yields `Ok(u)` when the
+/// closure returns `Some(u)`.
+///
+/// # Examples
+///
+/// ```
+/// use map_ok::FilterMapOk;
+///
+/// let input = vec![Ok("1"), Err("oops"), Ok("x"), Ok("3")];
+/// let mut iterator = input.into_iter().filter_map_ok(|s| s.parse::<i32>().ok());
+///
+/// assert_eq!(iterator.next(), Some(Ok(1)));
+/// assert_eq!(iterator.next(), Some(Err("oops")));
+/// assert_eq!(iterator.next(), Some(Ok(3)));
+/// assert_eq!(iterator.next(), None);
+/// ```
+pub trait FilterMapOk<T, E, F, U>: Sized
+where
+    F: Fn(T) -> Option<U>,
+{
+    type Iter: Iterator<Item = Result<U, E>>;
+
+    fn filter_map_ok(self, f: F) -> Self::Iter;
+}
+
+impl<I, T, E, U, F> FilterMapOk<T, E, F, U> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+    F: Fn(T) -> Option<U>,
+{
+    type Iter = FilterMapOkIter<Self, T, E, U, F>;
+
+    fn filter_map_ok(self, f: F) -> Self::Iter {
+        FilterMapOkIter {
+            iter: self,
+            f,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+/// A special iterator adapter that filters and maps the `Ok` values of an underlying iterator
+/// using a closure, similar to `Iterator::filter_map`, but passes every `Err` value through
+/// unchanged.
+///
+/// # Type arguments
+/// * `I` - The iterator itself.
+/// * `T` - The type of [`Ok`] variant of the iterated item.
+/// * `E` - The type of the [`Err`] variant of the iterated item.
+/// * `U` - The mapped type.
+/// * `F` - A [`Fn`] that maps from `T` to `Option<U>`.
+pub struct FilterMapOkIter<I, T, E, U, F> {
+    iter: I,
+    f: F,
+    _phantom: PhantomData<MapFn<T, E, U>>,
+}
+
+/// Helper type to simplify type definition.
+type MapFn<T, E, U> = fn(T, E) -> (U, Result<T, E>);
+
+impl<I, T, E, U, F> Iterator for FilterMapOkIter<I, T, E, U, F>
+where
+    I: Iterator<Item = Result<T, E>>,
+    F: Fn(T) -> Option<U>,
+{
+    type Item = Result<U, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.iter.next() {
+                Some(Ok(value)) => {
+                    if let Some(u) = (self.f)(value) {
+                        return Some(Ok(u));
+                    }
+                }
+                Some(Err(e)) => return Some(Err(e)),
+                None => return None,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn filter_map_ok_works() {
+        let input: Vec<Result<&str, &str>> = vec![Ok("1"), Err("oops"), Ok("x"), Ok("3")];
+        let mut iterator = input
+            .into_iter()
+            .filter_map_ok(|s| s.parse::<i32>().ok());
+
+        assert_eq!(iterator.next(), Some(Ok(1)));
+        assert_eq!(iterator.next(), Some(Err("oops")));
+        assert_eq!(iterator.next(), Some(Ok(3)));
+        assert_eq!(iterator.next(), None);
+    }
+}
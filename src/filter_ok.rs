@@ -0,0 +1,105 @@
+use std::marker::PhantomData;
+
+/// Represents an iterator that filters the Ok values using the given predicate.
+///
+/// This trait is implemented for iterators over `Result<T, E>`, allowing them to keep only
+/// the Ok values that satisfy a predicate, while letting every `Err` value pass through
+/// unchanged.
+///
+/// # Implementations
+///
+/// Implementations of this trait must provide an implementation for the `filter_ok` function,
+/// which receives a predicate `f` that is evaluated against every Ok value. It returns a
+/// `FilterOkIter` iterator that skips any Ok value for which the predicate returns `false`.
+///
+/// # Examples
+///
+/// ```
+/// use map_ok::FilterOk;
+///
+/// let input = vec![Ok(1), Err("oops"), Ok(2), Ok(3)];
+/// let mut iterator = input.into_iter().filter_ok(|v| v % 2 == 0);
+///
+/// assert_eq!(iterator.next(), Some(Err("oops")));
+/// assert_eq!(iterator.next(), Some(Ok(2)));
+/// assert_eq!(iterator.next(), None);
+/// ```
+pub trait FilterOk<T, E, F>: Sized
+where
+    F: Fn(&T) -> bool,
+{
+    type Iter: Iterator<Item = Result<T, E>>;
+
+    fn filter_ok(self, f: F) -> Self::Iter;
+}
+
+impl<I, T, E, F> FilterOk<T, E, F> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+    F: Fn(&T) -> bool,
+{
+    type Iter = FilterOkIter<Self, T, E, F>;
+
+    fn filter_ok(self, f: F) -> Self::Iter {
+        FilterOkIter {
+            iter: self,
+            f,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+/// A special iterator adapter that filters the `Ok` values of an underlying iterator using a
+/// predicate, similar to `Iterator::filter`, but passes every `Err` value through unchanged.
+///
+/// # Type arguments
+/// * `I` - The iterator itself.
+/// * `T` - The type of [`Ok`] variant of the iterated item.
+/// * `E` - The type of the [`Err`] variant of the iterated item.
+/// * `F` - A [`Fn`] predicate evaluated against `&T`.
+pub struct FilterOkIter<I, T, E, F> {
+    iter: I,
+    f: F,
+    _phantom: PhantomData<FilterFn<T, E>>,
+}
+
+/// Helper type to simplify type definition.
+type FilterFn<T, E> = fn(T, E) -> (T, Result<T, E>);
+
+impl<I, T, E, F> Iterator for FilterOkIter<I, T, E, F>
+where
+    I: Iterator<Item = Result<T, E>>,
+    F: Fn(&T) -> bool,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.iter.next() {
+                Some(Ok(value)) => {
+                    if (self.f)(&value) {
+                        return Some(Ok(value));
+                    }
+                }
+                Some(Err(e)) => return Some(Err(e)),
+                None => return None,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn filter_ok_works() {
+        let input: Vec<Result<i32, &str>> = vec![Ok(1), Err("oops"), Ok(2), Ok(3), Ok(4)];
+        let mut iterator = input.into_iter().filter_ok(|v| v % 2 == 0);
+
+        assert_eq!(iterator.next(), Some(Err("oops")));
+        assert_eq!(iterator.next(), Some(Ok(2)));
+        assert_eq!(iterator.next(), Some(Ok(4)));
+        assert_eq!(iterator.next(), None);
+    }
+}
@@ -0,0 +1,113 @@
+use std::marker::PhantomData;
+
+/// Represents an iterator that maps the Err values to another type using the given function.
+///
+/// This trait is implemented for iterators over `Result<T, E>`, allowing them to transform
+/// the Err values using a closure.
+///
+/// # Example
+///
+/// ```
+/// use std::iter::Iterator;
+/// use map_ok::MapErrIter;
+///
+/// pub trait MapErr<T, E>: Sized {
+///     fn map_err<E2, F>(self, f: F) -> MapErrIter<Self, T, E, E2, F>
+///     where
+///         F: Fn(E) -> E2;
+/// }
+/// ```
+///
+/// # Implementations
+///
+/// Implementations of this trait must provide an implementation for the `map_err` function, which receives
+/// a closure `f` that takes an Err value of type `E` and returns a value of type `E2`. It returns a `MapErrIter`
+/// iterator, which will apply the closure to each Err value encountered during iteration.
+pub trait MapErr<T, E, F, E2>: Sized
+where
+    F: Fn(E) -> E2,
+{
+    type Iter: Iterator<Item = Result<T, E2>>;
+
+    fn map_err(self, f: F) -> Self::Iter;
+}
+
+impl<I, T, E, E2, F> MapErr<T, E, F, E2> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+    F: Fn(E) -> E2,
+{
+    type Iter = MapErrIter<Self, T, E, E2, F>;
+
+    fn map_err(self, f: F) -> Self::Iter {
+        MapErrIter {
+            iter: self,
+            f,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+/// A special iterator adapter that applies a function to the elements of an underlying iterator,
+/// similar to `Result::map_err`, but leaves the `Ok` variant untouched.
+///
+/// # Type arguments
+/// * `I` - The iterator itself.
+/// * `T` - The type of [`Ok`] variant of the iterated item.
+/// * `E` - The type of the [`Err`] variant of the iterated item.
+/// * `E2` - The mapped error type.
+/// * `F` - A [`Fn`] that maps from `E` to `E2`.
+///
+/// # Examples
+///
+/// ```
+/// use map_ok::MapErr;
+///
+/// let input = vec![Ok(1), Err("oops"), Ok(2)];
+/// let mut iterator = input.into_iter().map_err(|e| e.len());
+///
+/// assert_eq!(iterator.next(), Some(Ok(1)));
+/// assert_eq!(iterator.next(), Some(Err(4)));
+/// assert_eq!(iterator.next(), Some(Ok(2)));
+/// assert_eq!(iterator.next(), None);
+/// ```
+pub struct MapErrIter<I, T, E, E2, F> {
+    iter: I,
+    f: F,
+    _phantom: PhantomData<MapFn<T, E, E2>>,
+}
+
+/// Helper type to simplify type definition.
+type MapFn<T, E, E2> = fn(T, E) -> (E2, Result<T, E>);
+
+impl<I, T, E, E2, F> Iterator for MapErrIter<I, T, E, E2, F>
+where
+    I: Iterator<Item = Result<T, E>>,
+    F: FnMut(E) -> E2,
+{
+    type Item = Result<T, E2>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next() {
+            Some(Ok(value)) => Some(Ok(value)),
+            Some(Err(e)) => Some(Err((self.f)(e))),
+            None => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn map_err_works() {
+        let input: Vec<Result<i32, &str>> = vec![Ok(10), Err("bad"), Ok(20)];
+        let mut iterator = input.into_iter().map_err(|e| e.to_uppercase());
+
+        assert_eq!(iterator.next(), Some(Ok(10)));
+        assert_eq!(iterator.next(), Some(Err("BAD".to_string())));
+        assert_eq!(iterator.next(), Some(Ok(20)));
+        assert_eq!(iterator.next(), None);
+    }
+}
@@ -118,6 +118,46 @@ where
             None => None,
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // The mapping is 1:1, so the bounds of the underlying iterator carry over exactly.
+        self.iter.size_hint()
+    }
+
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut f = self.f;
+        self.iter.fold(init, move |acc, item| {
+            let item = match item {
+                Ok(value) => Ok(f(value)),
+                Err(e) => Err(e),
+            };
+            g(acc, item)
+        })
+    }
+}
+
+impl<I, T, E, U, F> DoubleEndedIterator for MapOkIter<I, T, E, U, F>
+where
+    I: Iterator<Item = Result<T, E>> + DoubleEndedIterator,
+    F: FnMut(T) -> U,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self.iter.next_back() {
+            Some(Ok(value)) => Some(Ok((self.f)(value))),
+            Some(Err(e)) => Some(Err(e)),
+            None => None,
+        }
+    }
+}
+
+impl<I, T, E, U, F> ExactSizeIterator for MapOkIter<I, T, E, U, F>
+where
+    I: Iterator<Item = Result<T, E>> + ExactSizeIterator,
+    F: FnMut(T) -> U,
+{
 }
 
 #[cfg(test)]
@@ -157,4 +197,42 @@ mod tests {
         assert_eq!(iterator.next(), Some(Ok(30)));
         assert_eq!(iterator.next(), None);
     }
+
+    #[test]
+    fn size_hint_is_forwarded() {
+        let input: Vec<Result<i32, &str>> = vec![Ok(1), Err("oops"), Ok(2)];
+        let iterator = input.into_iter().map_ok(|v| v * 2);
+
+        assert_eq!(iterator.size_hint(), (3, Some(3)));
+    }
+
+    #[test]
+    fn fold_applies_f_to_ok_values() {
+        let input: Vec<Result<i32, &str>> = vec![Ok(1), Err("oops"), Ok(2)];
+        let sum = input
+            .into_iter()
+            .map_ok(|v| v * 10)
+            .fold(0, |acc, item| acc + item.unwrap_or(0));
+
+        assert_eq!(sum, 30);
+    }
+
+    #[test]
+    fn next_back_applies_f_to_ok_values() {
+        let input: Vec<Result<i32, &str>> = vec![Ok(1), Err("oops"), Ok(2)];
+        let mut iterator = input.into_iter().map_ok(|v| v * 10);
+
+        assert_eq!(iterator.next_back(), Some(Ok(20)));
+        assert_eq!(iterator.next_back(), Some(Err("oops")));
+        assert_eq!(iterator.next_back(), Some(Ok(10)));
+        assert_eq!(iterator.next_back(), None);
+    }
+
+    #[test]
+    fn len_is_forwarded() {
+        let input: Vec<Result<i32, &str>> = vec![Ok(1), Err("oops"), Ok(2)];
+        let iterator = input.into_iter().map_ok(|v| v * 2);
+
+        assert_eq!(iterator.len(), 3);
+    }
 }
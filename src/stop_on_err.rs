@@ -0,0 +1,132 @@
+/// Represents an iterator that can be converted into one which stops at the first `Err` and
+/// into a convenience method that collects the Ok values eagerly.
+///
+/// This trait is implemented for iterators over `Result<T, E>`, letting callers consume the
+/// Ok values in a plain `for` loop without unwrapping inside the loop body.
+///
+/// # Implementations
+///
+/// Implementations of this trait must provide an implementation for the `stop_on_err`
+/// function, which returns a [`StopOnErrIter`] iterator that yields `T` until the underlying
+/// iterator produces an `Err`, after which it buffers that error and reports `None` for every
+/// subsequent call to `next()`. The `collect_ok` function is a convenience that short-circuits
+/// on the first `Err` and collects every `Ok` value into a `Vec`.
+///
+/// # Examples
+///
+/// ```
+/// use map_ok::StopOnErr;
+///
+/// let input: Vec<Result<i32, &str>> = vec![Ok(1), Ok(2), Err("oops"), Ok(3)];
+/// let mut iterator = input.into_iter().stop_on_err();
+///
+/// let mut sum = 0;
+/// for v in &mut iterator {
+///     sum += v;
+/// }
+///
+/// assert_eq!(sum, 3);
+/// assert_eq!(iterator.into_result(), Err("oops"));
+/// ```
+pub trait StopOnErr<T, E>: Sized {
+    fn stop_on_err(self) -> StopOnErrIter<Self, E>;
+
+    fn collect_ok(self) -> Result<Vec<T>, E>;
+}
+
+impl<I, T, E> StopOnErr<T, E> for I
+where
+    I: Iterator<Item = Result<T, E>>,
+{
+    fn stop_on_err(self) -> StopOnErrIter<Self, E> {
+        StopOnErrIter {
+            iter: self,
+            err: None,
+        }
+    }
+
+    fn collect_ok(self) -> Result<Vec<T>, E> {
+        self.collect()
+    }
+}
+
+/// An iterator adapter that yields the `Ok` values of an underlying `Result` iterator, buffers
+/// the first `Err` it encounters, and then stops.
+///
+/// # Type arguments
+/// * `I` - The iterator itself.
+/// * `E` - The type of the [`Err`] variant of the iterated item.
+pub struct StopOnErrIter<I, E> {
+    iter: I,
+    err: Option<E>,
+}
+
+impl<I, E> StopOnErrIter<I, E> {
+    /// Consumes the iterator, returning the first error encountered during iteration, if any.
+    ///
+    /// `Ok(())` indicates that no `Err` was encountered by the calls to `next()` made so far;
+    /// it does not tell whether the underlying iterator was exhausted.
+    pub fn into_result(self) -> Result<(), E> {
+        match self.err {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<I, T, E> Iterator for StopOnErrIter<I, E>
+where
+    I: Iterator<Item = Result<T, E>>,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.err.is_some() {
+            return None;
+        }
+
+        match self.iter.next() {
+            Some(Ok(value)) => Some(value),
+            Some(Err(e)) => {
+                self.err = Some(e);
+                None
+            }
+            None => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stop_on_err_stops_at_first_error() {
+        let input: Vec<Result<i32, &str>> = vec![Ok(1), Ok(2), Err("oops"), Ok(3)];
+        let mut iterator = input.into_iter().stop_on_err();
+
+        assert_eq!(iterator.next(), Some(1));
+        assert_eq!(iterator.next(), Some(2));
+        assert_eq!(iterator.next(), None);
+        assert_eq!(iterator.next(), None);
+        assert_eq!(iterator.into_result(), Err("oops"));
+    }
+
+    #[test]
+    fn stop_on_err_reports_ok_when_never_erroring() {
+        let input: Vec<Result<i32, &str>> = vec![Ok(1), Ok(2)];
+        let mut iterator = input.into_iter().stop_on_err();
+
+        assert_eq!(iterator.by_ref().sum::<i32>(), 3);
+        assert_eq!(iterator.into_result(), Ok(()));
+    }
+
+    #[test]
+    fn collect_ok_short_circuits_on_first_error() {
+        let input: Vec<Result<i32, &str>> = vec![Ok(1), Ok(2), Err("oops"), Ok(3)];
+        assert_eq!(input.into_iter().collect_ok(), Err("oops"));
+
+        let input: Vec<Result<i32, &str>> = vec![Ok(1), Ok(2), Ok(3)];
+        assert_eq!(input.into_iter().collect_ok(), Ok(vec![1, 2, 3]));
+    }
+}